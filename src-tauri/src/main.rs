@@ -1,11 +1,13 @@
 use std::time::Duration;
 
-use btleplug::api::{Central, CharPropFlags, Manager as _, Peripheral as _, ScanFilter, WriteType};
+use btleplug::api::{
+    Central, CentralEvent, CharPropFlags, Manager as _, Peripheral as _, ScanFilter, WriteType,
+};
 use btleplug::platform::{Adapter, Manager, Peripheral};
 use futures::StreamExt;
 use serde::Serialize;
 use tauri::{AppHandle, Manager as _, State};
-use tokio::sync::Mutex;
+use tokio::sync::{broadcast, Mutex};
 use uuid::Uuid;
 
 const SERVICE_UUID: Uuid = Uuid::from_u128(0x0000e402_0000_1000_8000_00805f9b34fb);
@@ -13,13 +15,69 @@ const WRITE_UUID: Uuid = Uuid::from_u128(0x0000e403_0000_1000_8000_00805f9b34fb)
 const STATUS_UUID: Uuid = Uuid::from_u128(0x0000e404_0000_1000_8000_00805f9b34fb);
 
 const PREFIX_ID: u16 = 0x03e4;
-const FIRST_PACKET_DATA_MAX: usize = 11; // 20 - 3 - 6
-const NEXT_PACKET_DATA_MAX: usize = 17; // 20 - 3
 
-#[derive(Default)]
+// Default ATT MTU before negotiation (20 usable bytes after the 3-byte ATT header).
+const DEFAULT_MTU: u16 = 20;
+// Upper bound we ask the stack for; real devices/OSes will cap this to what they support.
+const REQUESTED_MTU: u16 = 517;
+// ATT Exchange-MTU overhead that applies to every ATT-layer write, separate
+// from our own `[index, count, length]` packet framing below.
+const ATT_HEADER_OVERHEAD: u16 = 3;
+// Framing overhead: [index, count, length] on every packet, plus
+// [prefix_id, opcode, data_len] on the first packet only.
+const PACKET_FRAMING_OVERHEAD: u16 = 3;
+const FIRST_PACKET_HEADER_OVERHEAD: u16 = 6;
+
+const STATUS_START: u16 = 0x0101;
+const STATUS_SSID: u16 = 0x0107;
+const STATUS_PWD: u16 = 0x0108;
+const STATUS_CERT_READY: u16 = 0x0103;
+const STATUS_SUCCESS: u16 = 0x0104;
+const STATUS_ERROR: u16 = 0x010A;
+const STATUS_CERT_ERR: u16 = 0x0109;
+
+// How many decoded status codes can be buffered per handshake waiter before
+// older ones are dropped.
+const STATUS_CHANNEL_CAPACITY: usize = 32;
+const DEFAULT_STATUS_TIMEOUT_MS: u64 = 5000;
+
+// Exponential-ish backoff schedule for reconnect attempts after an
+// unexpected disconnect: one attempt per entry, with the delay capped at 2 s.
+const RECONNECT_BACKOFF_MS: &[u64] = &[500, 1000, 2000, 2000, 2000];
+
+const CERT_OPCODE: u16 = 0xA004;
+// EAP identity for WPA2-Enterprise provisioning. Distinct from the PSK
+// password opcode (0xA003) since there's no firmware confirmation that slot
+// accepts anything but a literal WiFi password.
+const IDENTITY_OPCODE: u16 = 0xA005;
+
+// Interval for the read-poll fallback when the status characteristic only
+// supports READ (no NOTIFY/INDICATE).
+const STATUS_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
 struct AppState {
     adapter: Mutex<Option<Adapter>>,
     peripheral: Mutex<Option<Peripheral>>,
+    peripheral_id: Mutex<Option<String>>,
+    mtu: Mutex<u16>,
+    status_tx: broadcast::Sender<u16>,
+    auto_reconnect: Mutex<bool>,
+    scan_task: Mutex<Option<tauri::async_runtime::JoinHandle<()>>>,
+}
+
+impl Default for AppState {
+    fn default() -> Self {
+        let (status_tx, _) = broadcast::channel(STATUS_CHANNEL_CAPACITY);
+        Self {
+            adapter: Mutex::new(None),
+            peripheral: Mutex::new(None),
+            peripheral_id: Mutex::new(None),
+            mtu: Mutex::new(0),
+            status_tx,
+            auto_reconnect: Mutex::new(true),
+            scan_task: Mutex::new(None),
+        }
+    }
 }
 
 #[derive(Serialize)]
@@ -37,6 +95,18 @@ struct StatusEvent {
     hex: String,
 }
 
+#[derive(Clone, Serialize)]
+struct ConnectionEvent {
+    state: String,
+    attempt: Option<u32>,
+}
+
+#[derive(Clone, Serialize)]
+struct CertProgressEvent {
+    bytes_sent: usize,
+    total_bytes: usize,
+}
+
 fn status_name(code: u16) -> &'static str {
     match code {
         0x0100 => "READY",
@@ -78,6 +148,31 @@ async fn ensure_adapter(state: &AppState) -> Result<Adapter, String> {
     Ok(adapter)
 }
 
+/// Returns the negotiated MTU for the active connection, or `DEFAULT_MTU` if
+/// none has been negotiated yet (e.g. no device connected, or the platform
+/// doesn't expose MTU negotiation).
+async fn current_mtu(state: &AppState) -> u16 {
+    match *state.mtu.lock().await {
+        0 => DEFAULT_MTU,
+        mtu => mtu,
+    }
+}
+
+/// Requests a larger MTU and returns an estimate of the usable bytes per write.
+///
+/// btleplug only reports whether the Exchange-MTU *request* was sent
+/// successfully, not what the peer/OS stack actually granted, so a real
+/// connection may settle on something far below `REQUESTED_MTU`. On success
+/// this assumes the full `REQUESTED_MTU` was granted and only subtracts the
+/// ATT header overhead, so the estimate can be optimistic on some
+/// platforms/peers; on failure it returns `DEFAULT_MTU`.
+async fn negotiate_mtu(peripheral: &Peripheral) -> u16 {
+    match peripheral.request_mtu(REQUESTED_MTU).await {
+        Ok(()) => REQUESTED_MTU.saturating_sub(ATT_HEADER_OVERHEAD),
+        Err(_) => DEFAULT_MTU,
+    }
+}
+
 async fn get_connected_peripheral(state: &AppState) -> Result<Peripheral, String> {
     let peripheral = state
         .peripheral
@@ -93,21 +188,42 @@ async fn get_connected_peripheral(state: &AppState) -> Result<Peripheral, String
     }
 }
 
-fn split_payload(data: &[u8]) -> Vec<Vec<u8>> {
+/// Splits `data` into packet-sized chunks for the given negotiated `mtu`.
+///
+/// The first packet leaves room for the 6-byte `[prefix_id, opcode, data_len]`
+/// header in addition to the 3-byte `[index, count, length]` framing; later
+/// packets only carry the framing. When the transfer needs widened framing
+/// (see `needs_wide_framing`), the split is redone with room for the 6-byte
+/// `u16` framing instead.
+fn split_payload(data: &[u8], mtu: u16) -> Vec<Vec<u8>> {
+    let chunks = split_payload_with_framing(data, mtu, PACKET_FRAMING_OVERHEAD);
+    if needs_wide_framing(&chunks) {
+        split_payload_with_framing(data, mtu, 2 * PACKET_FRAMING_OVERHEAD)
+    } else {
+        chunks
+    }
+}
+
+fn split_payload_with_framing(data: &[u8], mtu: u16, framing: u16) -> Vec<Vec<u8>> {
+    let first_max = mtu.saturating_sub(framing + FIRST_PACKET_HEADER_OVERHEAD) as usize;
+    let next_max = mtu.saturating_sub(framing) as usize;
+    let first_max = first_max.max(1);
+    let next_max = next_max.max(1);
+
     if data.is_empty() {
         return vec![Vec::new()];
     }
 
     let mut packets = Vec::new();
-    if data.len() <= FIRST_PACKET_DATA_MAX {
+    if data.len() <= first_max {
         packets.push(data.to_vec());
         return packets;
     }
 
-    packets.push(data[..FIRST_PACKET_DATA_MAX].to_vec());
-    let mut offset = FIRST_PACKET_DATA_MAX;
+    packets.push(data[..first_max].to_vec());
+    let mut offset = first_max;
     while offset < data.len() {
-        let end = (offset + NEXT_PACKET_DATA_MAX).min(data.len());
+        let end = (offset + next_max).min(data.len());
         packets.push(data[offset..end].to_vec());
         offset = end;
     }
@@ -115,31 +231,68 @@ fn split_payload(data: &[u8]) -> Vec<Vec<u8>> {
     packets
 }
 
-fn build_packets(opcode: u16, data: &[u8]) -> Vec<Vec<u8>> {
+/// A chunk's on-wire length field, counting the first-packet header when
+/// applicable, so both the framing-width decision and the field itself agree
+/// on what "length" means for that packet.
+fn wire_length(idx: usize, chunk: &[u8]) -> usize {
+    if idx == 0 {
+        FIRST_PACKET_HEADER_OVERHEAD as usize + chunk.len()
+    } else {
+        chunk.len()
+    }
+}
+
+/// Whether any packet in this transfer needs the widened `u16` framing:
+/// either the transfer spans more than 255 packets (`index`/`count` would
+/// overflow `u8`), or any single packet's `length` field would overflow `u8`
+/// on its own — which happens well before the packet-count threshold once a
+/// negotiated MTU lets a chunk exceed ~249-255 bytes.
+fn needs_wide_framing(chunks: &[Vec<u8>]) -> bool {
+    chunks.len() > 255
+        || chunks
+            .iter()
+            .enumerate()
+            .any(|(idx, chunk)| wire_length(idx, chunk) > u8::MAX as usize)
+}
+
+/// Frames a single chunk as `[index, count, length]` followed by the
+/// first-packet `[prefix_id, opcode, data_len]` header (packet one only) and
+/// the chunk bytes. When `wide` is set (see `needs_wide_framing`), `index`,
+/// `count`, and `length` all widen from `u8` to little-endian `u16` together,
+/// since the firmware needs a consistent field width within one packet to
+/// parse it; this covers both large packet counts and large per-packet MTUs.
+fn frame_packet(idx: usize, count: usize, chunk: &[u8], opcode: u16, data_len: u16, wide: bool) -> Vec<u8> {
+    let mut packet = Vec::new();
+    let index = idx + 1;
+    let length = wire_length(idx, chunk);
+
+    if wide {
+        packet.extend((index as u16).to_le_bytes());
+        packet.extend((count as u16).to_le_bytes());
+        packet.extend((length as u16).to_le_bytes());
+    } else {
+        packet.extend([index as u8, count as u8, length as u8]);
+    }
+
+    if idx == 0 {
+        packet.extend(PREFIX_ID.to_le_bytes());
+        packet.extend(opcode.to_le_bytes());
+        packet.extend(data_len.to_le_bytes());
+    }
+    packet.extend(chunk);
+    packet
+}
+
+fn build_packets(opcode: u16, data: &[u8], mtu: u16) -> Vec<Vec<u8>> {
     let data_len = data.len() as u16;
-    let chunks = split_payload(data);
-    let raw_count = chunks.len() as u8;
+    let chunks = split_payload(data, mtu);
+    let count = chunks.len();
+    let wide = needs_wide_framing(&chunks);
 
     chunks
-        .into_iter()
+        .iter()
         .enumerate()
-        .map(|(idx, chunk)| {
-            let mut packet = Vec::new();
-            let raw_index = (idx + 1) as u8;
-            if idx == 0 {
-                let raw_length = (6 + chunk.len()) as u8;
-                packet.extend([raw_index, raw_count, raw_length]);
-                packet.extend(PREFIX_ID.to_le_bytes());
-                packet.extend(opcode.to_le_bytes());
-                packet.extend(data_len.to_le_bytes());
-                packet.extend(chunk);
-            } else {
-                let raw_length = chunk.len() as u8;
-                packet.extend([raw_index, raw_count, raw_length]);
-                packet.extend(chunk);
-            }
-            packet
-        })
+        .map(|(idx, chunk)| frame_packet(idx, count, chunk, opcode, data_len, wide))
         .collect()
 }
 
@@ -203,7 +356,11 @@ async fn find_characteristics(
     Ok((write_char, status_char))
 }
 
-async fn listen_status_notifications(app: AppHandle, peripheral: Peripheral) {
+async fn listen_status_notifications(
+    app: AppHandle,
+    peripheral: Peripheral,
+    status_tx: broadcast::Sender<u16>,
+) {
     let mut stream = match peripheral.notifications().await {
         Ok(stream) => stream,
         Err(err) => {
@@ -227,12 +384,117 @@ async fn listen_status_notifications(app: AppHandle, peripheral: Peripheral) {
             continue;
         }
         let code = u16::from_le_bytes([notification.value[0], notification.value[1]]);
-        let event = StatusEvent {
+        publish_status(&app, &status_tx, code);
+    }
+}
+
+/// Decodes a status code and both broadcasts it (for handshake waiters) and
+/// emits it to the frontend. Shared by the notify path and the read-poll
+/// fallback below so both surface status identically.
+fn publish_status(app: &AppHandle, status_tx: &broadcast::Sender<u16>, code: u16) {
+    let _ = status_tx.send(code);
+    let _ = app.emit_all(
+        "netcfg_status",
+        StatusEvent {
             code,
             name: status_name(code).to_string(),
             hex: format!("0x{code:04X}"),
+        },
+    );
+}
+
+/// Polls a READ-only status characteristic on an interval and publishes a
+/// status update whenever the decoded value changes, debouncing repeats.
+/// Mirrors the notify path so the status-driven handshake works uniformly
+/// regardless of whether the characteristic supports NOTIFY/INDICATE.
+async fn poll_status_until_disconnected(
+    app: AppHandle,
+    peripheral: Peripheral,
+    status_char: btleplug::api::Characteristic,
+    status_tx: broadcast::Sender<u16>,
+) {
+    let mut last_code = None;
+    loop {
+        if !matches!(peripheral.is_connected().await, Ok(true)) {
+            return;
+        }
+
+        match peripheral.read(&status_char).await {
+            Ok(value) if value.len() >= 2 => {
+                let code = u16::from_le_bytes([value[0], value[1]]);
+                if last_code != Some(code) {
+                    last_code = Some(code);
+                    publish_status(&app, &status_tx, code);
+                }
+            }
+            Ok(_) => {}
+            Err(_) => return,
+        }
+
+        tokio::time::sleep(STATUS_POLL_INTERVAL).await;
+    }
+}
+
+/// Waits on the status broadcast channel for `expected`, aborting early with
+/// an error if the device reports `ERROR`/`CERT_ERR` or the handshake times
+/// out. The error is a plain message string naming the status; there is no
+/// dedicated error type to match on.
+async fn await_status(
+    mut rx: broadcast::Receiver<u16>,
+    expected: u16,
+    timeout: Duration,
+) -> Result<(), String> {
+    let deadline = tokio::time::Instant::now() + timeout;
+    loop {
+        let remaining = deadline.saturating_duration_since(tokio::time::Instant::now());
+        let code = match tokio::time::timeout(remaining, rx.recv()).await {
+            Ok(Ok(code)) => code,
+            // A lagged receiver has only missed some intermediate codes, not
+            // lost the channel; keep waiting rather than failing outright.
+            Ok(Err(broadcast::error::RecvError::Lagged(_))) => continue,
+            Ok(Err(broadcast::error::RecvError::Closed)) => {
+                return Err("Status channel closed".to_string())
+            }
+            Err(_) => {
+                return Err(format!(
+                    "Timed out waiting for {} status",
+                    status_name(expected)
+                ))
+            }
         };
-        let _ = app.emit_all("netcfg_status", event);
+
+        if code == expected {
+            return Ok(());
+        }
+        if code == STATUS_ERROR || code == STATUS_CERT_ERR {
+            return Err(format!(
+                "Device reported {} while waiting for {}",
+                status_name(code),
+                status_name(expected)
+            ));
+        }
+    }
+}
+
+/// Builds a `DeviceInfo` from a discovered peripheral's advertised properties,
+/// applying the same name/manufacturer-data/service-data marker matching used
+/// by both the bounded and streaming scan paths.
+fn device_info(peripheral_id: String, props: btleplug::api::PeripheralProperties) -> DeviceInfo {
+    let name = props.local_name.unwrap_or_else(|| "Unknown".to_string());
+    let mut matched = matches_device_name(&name);
+
+    if !matched && props.manufacturer_data.values().any(|data| contains_marker(data)) {
+        matched = true;
+    }
+    if !matched && props.service_data.values().any(|data| contains_marker(data)) {
+        matched = true;
+    }
+
+    DeviceInfo {
+        id: peripheral_id,
+        name,
+        rssi: props.rssi,
+        matched,
     }
 }
 
@@ -256,35 +518,7 @@ async fn scan_devices(
     for peripheral in peripherals {
         let props = peripheral.properties().await.map_err(|e| e.to_string())?;
         if let Some(props) = props {
-            let name = props.local_name.unwrap_or_else(|| "Unknown".to_string());
-            let mut matched = matches_device_name(&name);
-
-            if !matched {
-                if props
-                    .manufacturer_data
-                    .values()
-                    .any(|data| contains_marker(data))
-                {
-                    matched = true;
-                }
-            }
-
-            if !matched {
-                if props
-                    .service_data
-                    .values()
-                    .any(|data| contains_marker(data))
-                {
-                    matched = true;
-                }
-            }
-
-            devices.push(DeviceInfo {
-                id: peripheral.id().to_string(),
-                name,
-                rssi: props.rssi,
-                matched,
-            });
+            devices.push(device_info(peripheral.id().to_string(), props));
         }
     }
 
@@ -293,6 +527,77 @@ async fn scan_devices(
     Ok(devices)
 }
 
+/// Starts an open-ended scan and streams `netcfg_device` events as peripherals
+/// are discovered or updated, instead of waiting for a fixed timeout and
+/// enumerating once. Pass `filter_by_service: true` to cut noise on crowded
+/// channels by only matching the NETCFG_BLE service UUID in the advertisement.
+#[tauri::command]
+async fn start_scan(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    filter_by_service: Option<bool>,
+) -> Result<(), String> {
+    let adapter = ensure_adapter(&state).await?;
+    let filter = if filter_by_service.unwrap_or(false) {
+        ScanFilter {
+            services: vec![SERVICE_UUID],
+        }
+    } else {
+        ScanFilter::default()
+    };
+
+    adapter.start_scan(filter).await.map_err(|e| e.to_string())?;
+
+    let mut scan_task = state.scan_task.lock().await;
+    if let Some(previous) = scan_task.take() {
+        previous.abort();
+    }
+    *scan_task = Some(tauri::async_runtime::spawn(stream_scan_events(app, adapter)));
+
+    Ok(())
+}
+
+#[tauri::command]
+async fn stop_scan(state: State<'_, AppState>) -> Result<(), String> {
+    let adapter = ensure_adapter(&state).await?;
+    adapter.stop_scan().await.map_err(|e| e.to_string())?;
+
+    if let Some(task) = state.scan_task.lock().await.take() {
+        task.abort();
+    }
+
+    Ok(())
+}
+
+async fn stream_scan_events(app: AppHandle, adapter: Adapter) {
+    let mut events = match adapter.events().await {
+        Ok(events) => events,
+        Err(_) => return,
+    };
+
+    while let Some(event) = events.next().await {
+        let discovered_id = match event {
+            CentralEvent::DeviceDiscovered(id) | CentralEvent::DeviceUpdated(id) => Some(id),
+            _ => None,
+        };
+        let Some(id) = discovered_id else {
+            continue;
+        };
+
+        let Ok(peripheral) = adapter.peripheral(&id).await else {
+            continue;
+        };
+        let Ok(Some(props)) = peripheral.properties().await else {
+            continue;
+        };
+
+        let _ = app.emit_all(
+            "netcfg_device",
+            device_info(peripheral.id().to_string(), props),
+        );
+    }
+}
+
 #[tauri::command]
 async fn connect_device(state: State<'_, AppState>, app: AppHandle, id: String) -> Result<(), String> {
     let adapter = ensure_adapter(&state).await?;
@@ -326,6 +631,8 @@ async fn connect_device(state: State<'_, AppState>, app: AppHandle, id: String)
         .map_err(|e| e.to_string())?;
     tokio::time::sleep(Duration::from_millis(200)).await;
 
+    *state.mtu.lock().await = negotiate_mtu(&peripheral).await;
+
     if !peripheral
         .services()
         .iter()
@@ -344,6 +651,11 @@ async fn connect_device(state: State<'_, AppState>, app: AppHandle, id: String)
             .subscribe(&status_char)
             .await
             .map_err(|e| e.to_string())?;
+        tauri::async_runtime::spawn(listen_status_notifications(
+            app.clone(),
+            peripheral.clone(),
+            state.status_tx.clone(),
+        ));
     } else {
         let _ = app.emit_all(
             "netcfg_status",
@@ -353,11 +665,146 @@ async fn connect_device(state: State<'_, AppState>, app: AppHandle, id: String)
                 hex: "0x0000".to_string(),
             },
         );
+        tauri::async_runtime::spawn(poll_status_until_disconnected(
+            app.clone(),
+            peripheral.clone(),
+            status_char,
+            state.status_tx.clone(),
+        ));
     }
 
     *state.peripheral.lock().await = Some(peripheral.clone());
-    tauri::async_runtime::spawn(listen_status_notifications(app, peripheral));
+    *state.peripheral_id.lock().await = Some(id.clone());
+    tauri::async_runtime::spawn(watch_connection(app, id));
+
+    Ok(())
+}
+
+fn emit_connection_event(app: &AppHandle, connection_state: &str, attempt: Option<u32>) {
+    let _ = app.emit_all(
+        "netcfg_connection",
+        ConnectionEvent {
+            state: connection_state.to_string(),
+            attempt,
+        },
+    );
+}
+
+/// Watches the adapter for a `DeviceDisconnected` event matching
+/// `peripheral_id` and, on disconnect, either attempts reconnection (if
+/// auto-reconnect is enabled) or gives up and clears the stored peripheral.
+/// Keeps watching after a successful reconnect so later drops are handled too.
+async fn watch_connection(app: AppHandle, peripheral_id: String) {
+    let state = app.state::<AppState>();
+    let Some(adapter) = state.adapter.lock().await.clone() else {
+        return;
+    };
+    let Ok(mut events) = adapter.events().await else {
+        return;
+    };
+
+    while let Some(event) = events.next().await {
+        let CentralEvent::DeviceDisconnected(id) = event else {
+            continue;
+        };
+        if id.to_string() != peripheral_id {
+            continue;
+        }
+        if state.peripheral_id.lock().await.as_deref() != Some(peripheral_id.as_str()) {
+            // Superseded by a manual disconnect or a different connection.
+            return;
+        }
+
+        emit_connection_event(&app, "disconnected", None);
+
+        let auto_reconnect = *state.auto_reconnect.lock().await;
+        if auto_reconnect && reconnect(&app, &state, &adapter, &peripheral_id).await {
+            continue;
+        }
+
+        *state.peripheral.lock().await = None;
+        *state.peripheral_id.lock().await = None;
+        return;
+    }
+}
+
+/// Attempts to reconnect to `peripheral_id` with the `RECONNECT_BACKOFF_MS`
+/// schedule, re-discovering services and re-subscribing to status so an
+/// in-flight `configure_wifi` can resume. Returns whether it succeeded.
+async fn reconnect(
+    app: &AppHandle,
+    state: &AppState,
+    adapter: &Adapter,
+    peripheral_id: &str,
+) -> bool {
+    for (attempt, delay_ms) in RECONNECT_BACKOFF_MS.iter().enumerate() {
+        tokio::time::sleep(Duration::from_millis(*delay_ms)).await;
+        emit_connection_event(app, "reconnecting", Some(attempt as u32 + 1));
 
+        let Ok(peripherals) = adapter.peripherals().await else {
+            continue;
+        };
+        let Some(peripheral) = peripherals
+            .into_iter()
+            .find(|p| p.id().to_string() == peripheral_id)
+        else {
+            continue;
+        };
+
+        if peripheral.connect().await.is_err() {
+            continue;
+        }
+        if peripheral.discover_services().await.is_err() {
+            continue;
+        }
+
+        // A fresh connection does not retain the previous link's negotiated
+        // ATT MTU, so it must be re-requested before resuming traffic.
+        *state.mtu.lock().await = negotiate_mtu(&peripheral).await;
+
+        let Ok((_write_char, status_char)) = find_characteristics(&peripheral).await else {
+            continue;
+        };
+        if status_char.properties.contains(CharPropFlags::NOTIFY)
+            || status_char.properties.contains(CharPropFlags::INDICATE)
+        {
+            if peripheral.subscribe(&status_char).await.is_err() {
+                continue;
+            }
+            tauri::async_runtime::spawn(listen_status_notifications(
+                app.clone(),
+                peripheral.clone(),
+                state.status_tx.clone(),
+            ));
+        } else {
+            tauri::async_runtime::spawn(poll_status_until_disconnected(
+                app.clone(),
+                peripheral.clone(),
+                status_char,
+                state.status_tx.clone(),
+            ));
+        }
+
+        *state.peripheral.lock().await = Some(peripheral);
+        emit_connection_event(app, "connected", None);
+        return true;
+    }
+
+    false
+}
+
+#[tauri::command]
+async fn set_auto_reconnect(state: State<'_, AppState>, enabled: bool) -> Result<(), String> {
+    *state.auto_reconnect.lock().await = enabled;
+    Ok(())
+}
+
+#[tauri::command]
+async fn set_chunk_mtu(state: State<'_, AppState>, mtu: u16) -> Result<(), String> {
+    if mtu <= PACKET_FRAMING_OVERHEAD + FIRST_PACKET_HEADER_OVERHEAD {
+        return Err("MTU too small to carry any payload".to_string());
+    }
+    *state.mtu.lock().await = mtu;
     Ok(())
 }
 
@@ -369,12 +816,13 @@ async fn disconnect_device(state: State<'_, AppState>) -> Result<(), String> {
         .await
         .map_err(|e| e.to_string())?;
     *state.peripheral.lock().await = None;
+    *state.peripheral_id.lock().await = None;
     Ok(())
 }
 
 #[tauri::command]
 async fn send_start(state: State<'_, AppState>) -> Result<(), String> {
-    send_opcode(state.inner(), 0xA001, &[]).await
+    send_opcode(state.inner(), 0xA001, &[], None).await
 }
 
 #[tauri::command]
@@ -383,7 +831,7 @@ async fn send_ssid(state: State<'_, AppState>, ssid: String) -> Result<(), Strin
     if bytes.len() > 36 {
         return Err("SSID length exceeds 36 bytes".to_string());
     }
-    send_opcode(state.inner(), 0xA002, bytes).await
+    send_opcode(state.inner(), 0xA002, bytes, None).await
 }
 
 #[tauri::command]
@@ -392,17 +840,17 @@ async fn send_password(state: State<'_, AppState>, password: String) -> Result<(
     if bytes.len() > 64 {
         return Err("Password length exceeds 64 bytes".to_string());
     }
-    send_opcode(state.inner(), 0xA003, bytes).await
+    send_opcode(state.inner(), 0xA003, bytes, None).await
 }
 
 #[tauri::command]
 async fn send_done(state: State<'_, AppState>) -> Result<(), String> {
-    send_opcode(state.inner(), 0xA010, &[]).await
+    send_opcode(state.inner(), 0xA010, &[], None).await
 }
 
 #[tauri::command]
 async fn send_reboot(state: State<'_, AppState>) -> Result<(), String> {
-    send_opcode(state.inner(), 0xA011, &[]).await
+    send_opcode(state.inner(), 0xA011, &[], None).await
 }
 
 #[tauri::command]
@@ -410,6 +858,7 @@ async fn configure_wifi(
     state: State<'_, AppState>,
     ssid: String,
     password: String,
+    timeout_ms: Option<u64>,
 ) -> Result<(), String> {
     let ssid_bytes = ssid.as_bytes();
     if ssid_bytes.len() > 36 {
@@ -421,17 +870,142 @@ async fn configure_wifi(
     }
 
     let state_ref = state.inner();
-    send_opcode(state_ref, 0xA001, &[]).await?;
-    send_opcode(state_ref, 0xA002, ssid_bytes).await?;
-    send_opcode(state_ref, 0xA003, pwd_bytes).await?;
-    send_opcode(state_ref, 0xA010, &[]).await?;
+    let timeout = Duration::from_millis(timeout_ms.unwrap_or(DEFAULT_STATUS_TIMEOUT_MS));
+
+    send_opcode(state_ref, 0xA001, &[], Some((STATUS_START, timeout))).await?;
+    send_opcode(state_ref, 0xA002, ssid_bytes, Some((STATUS_SSID, timeout))).await?;
+    send_opcode(state_ref, 0xA003, pwd_bytes, Some((STATUS_PWD, timeout))).await?;
+    send_opcode(state_ref, 0xA010, &[], Some((STATUS_SUCCESS, timeout))).await?;
+    Ok(())
+}
+
+/// Streams a CA/client certificate to the device over the `CERT_OPCODE`
+/// channel, gated on the device reporting `CERT_READY` before the bulk
+/// transfer begins. Aborts early if `CERT_ERR`/`ERROR` arrives mid-transfer,
+/// and emits `netcfg_cert_progress` after every packet.
+#[tauri::command]
+async fn send_certificate(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    pem: Vec<u8>,
+) -> Result<(), String> {
+    let state_ref = state.inner();
+    // Subscribe before anything else so we can't miss a CERT_READY that was
+    // already in flight from whatever step the caller sent before this.
+    let ready_rx = state_ref.status_tx.subscribe();
+    send_certificate_with_ready(state_ref, app, pem, ready_rx).await
+}
+
+/// Streams `pem` once `ready_rx` observes `CERT_READY`. Callers that need to
+/// avoid racing the opcode that triggers `CERT_READY` should subscribe
+/// *before* sending that opcode and pass the receiver in here, rather than
+/// letting this function subscribe only after being invoked.
+async fn send_certificate_with_ready(
+    state: &AppState,
+    app: AppHandle,
+    pem: Vec<u8>,
+    ready_rx: broadcast::Receiver<u16>,
+) -> Result<(), String> {
+    let peripheral = get_connected_peripheral(state).await?;
+    let (write_char, _status_char) = find_characteristics(&peripheral).await?;
+    let write_type = if write_char
+        .properties
+        .contains(CharPropFlags::WRITE_WITHOUT_RESPONSE)
+    {
+        WriteType::WithoutResponse
+    } else {
+        WriteType::WithResponse
+    };
+    let mtu = current_mtu(state).await;
+    let timeout = Duration::from_millis(DEFAULT_STATUS_TIMEOUT_MS);
+
+    await_status(ready_rx, STATUS_CERT_READY, timeout).await?;
+
+    let chunks = split_payload(&pem, mtu);
+    let count = chunks.len();
+    let wide = needs_wide_framing(&chunks);
+    let total_bytes = pem.len();
+    let mut bytes_sent = 0usize;
+    let mut err_rx = state.status_tx.subscribe();
+
+    for (idx, chunk) in chunks.iter().enumerate() {
+        if let Ok(code) = err_rx.try_recv() {
+            if code == STATUS_CERT_ERR || code == STATUS_ERROR {
+                return Err(format!(
+                    "Device reported {} during certificate transfer",
+                    status_name(code)
+                ));
+            }
+        }
+
+        let packet = frame_packet(idx, count, chunk, CERT_OPCODE, total_bytes as u16, wide);
+        write_packets(&peripheral, &write_char, write_type, vec![packet]).await?;
+
+        bytes_sent += chunk.len();
+        let _ = app.emit_all(
+            "netcfg_cert_progress",
+            CertProgressEvent {
+                bytes_sent,
+                total_bytes,
+            },
+        );
+    }
+
+    Ok(())
+}
+
+/// Convenience command for WPA2-Enterprise provisioning: sequences
+/// START -> SSID -> IDENTITY -> CERT -> DONE, using a dedicated opcode for
+/// the EAP identity rather than repurposing the PSK password slot.
+#[tauri::command]
+async fn configure_wifi_enterprise(
+    state: State<'_, AppState>,
+    app: AppHandle,
+    ssid: String,
+    identity: String,
+    cert: Vec<u8>,
+) -> Result<(), String> {
+    let ssid_bytes = ssid.as_bytes();
+    if ssid_bytes.len() > 36 {
+        return Err("SSID length exceeds 36 bytes".to_string());
+    }
+    let identity_bytes = identity.as_bytes();
+    if identity_bytes.len() > 64 {
+        return Err("Identity length exceeds 64 bytes".to_string());
+    }
+
+    let state_ref = state.inner();
+    let timeout = Duration::from_millis(DEFAULT_STATUS_TIMEOUT_MS);
+
+    send_opcode(state_ref, 0xA001, &[], Some((STATUS_START, timeout))).await?;
+    send_opcode(state_ref, 0xA002, ssid_bytes, Some((STATUS_SSID, timeout))).await?;
+
+    // Subscribe before sending IDENTITY so a CERT_READY the device fires in
+    // the gap between that write completing and send_certificate starting
+    // isn't missed (tokio broadcast receivers never see pre-subscription
+    // sends).
+    let cert_ready_rx = state_ref.status_tx.subscribe();
+    send_opcode(state_ref, IDENTITY_OPCODE, identity_bytes, None).await?;
+
+    send_certificate_with_ready(state_ref, app.clone(), cert, cert_ready_rx).await?;
+    send_opcode(state_ref, 0xA010, &[], Some((STATUS_SUCCESS, timeout))).await?;
     Ok(())
 }
 
-async fn send_opcode(state: &AppState, opcode: u16, data: &[u8]) -> Result<(), String> {
+/// Sends a single opcode packet sequence. When `ack` is set, subscribes to
+/// the status broadcast before writing and waits for the expected code
+/// (bounded by the given timeout) before returning, so callers can drive a
+/// request/response handshake instead of firing blind.
+async fn send_opcode(
+    state: &AppState,
+    opcode: u16,
+    data: &[u8],
+    ack: Option<(u16, Duration)>,
+) -> Result<(), String> {
     let peripheral = get_connected_peripheral(state).await?;
     let (write_char, _status_char) = find_characteristics(&peripheral).await?;
-    let packets = build_packets(opcode, data);
+    let mtu = current_mtu(state).await;
+    let packets = build_packets(opcode, data, mtu);
     let write_type = if write_char
         .properties
         .contains(CharPropFlags::WRITE_WITHOUT_RESPONSE)
@@ -440,7 +1014,16 @@ async fn send_opcode(state: &AppState, opcode: u16, data: &[u8]) -> Result<(), S
     } else {
         WriteType::WithResponse
     };
-    write_packets(&peripheral, &write_char, write_type, packets).await
+
+    let ack_rx = ack.map(|(expected, timeout)| (state.status_tx.subscribe(), expected, timeout));
+
+    write_packets(&peripheral, &write_char, write_type, packets).await?;
+
+    if let Some((rx, expected, timeout)) = ack_rx {
+        await_status(rx, expected, timeout).await?;
+    }
+
+    Ok(())
 }
 
 fn main() {
@@ -448,9 +1031,15 @@ fn main() {
         .manage(AppState::default())
         .invoke_handler(tauri::generate_handler![
             scan_devices,
+            start_scan,
+            stop_scan,
             connect_device,
             disconnect_device,
+            set_chunk_mtu,
+            set_auto_reconnect,
             configure_wifi,
+            configure_wifi_enterprise,
+            send_certificate,
             send_start,
             send_ssid,
             send_password,